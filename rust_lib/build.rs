@@ -9,4 +9,20 @@ fn main() {
     cbindgen::generate_with_config(&crate_dir, config)
         .unwrap()
         .write_to_file("target/rust_lib.h");
+
+    // The resolved version of sqlx, for `sqlx4k_version`.
+    let lock = std::fs::read_to_string(format!("{}/Cargo.lock", crate_dir)).unwrap_or_default();
+    let sqlx_version = lock
+        .split("[[package]]")
+        .find(|package| package.contains("\nname = \"sqlx\"\n"))
+        .and_then(|package| package.split("\nversion = \"").nth(1))
+        .and_then(|version| version.split('"').next())
+        .unwrap_or("unknown");
+    println!("cargo:rustc-env=SQLX4K_SQLX_VERSION={}", sqlx_version);
+    // Declaring any file to watch replaces the default of watching the whole package,
+    // so list the sources the header is generated from as well.
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src");
 }
@@ -45,14 +45,14 @@ fun main() {
 
         val r1 = pg.fetchAll("select * from sqlx4k;") {
             val id: Sqlx4k.Row.Column = get("id")
-            Test(id = id.value.toInt())
+            Test(id = id.value!!.toInt())
         }
 
         println(r1)
 
         pg.fetchAll("select * from :table;", mapOf("table" to "sqlx4k")) {
             val id: Sqlx4k.Row.Column = get("id")
-            Test(id = id.value.toInt())
+            Test(id = id.value!!.toInt())
         }
 
         pg.fetchAll("select 1;") {
@@ -87,7 +87,7 @@ fun main() {
 
         val test = pg.fetchAll("select * from sqlx4k;") {
             val id: Sqlx4k.Row.Column = get("id")
-            Test(id = id.value.toInt())
+            Test(id = id.value!!.toInt())
         }
         println(test)
 
@@ -97,11 +97,11 @@ fun main() {
                     repeat(1_000) {
                         pg.fetchAll("select * from sqlx4k limit 1000;") {
                             val id: Sqlx4k.Row.Column = get("id")
-                            Test(id = id.value.toInt())
+                            Test(id = id.value!!.toInt())
                         }
                         pg.fetchAll("select * from sqlx4k;") {
                             val id: Sqlx4k.Row.Column = get("id")
-                            Test(id = id.value.toInt())
+                            Test(id = id.value!!.toInt())
                         }
                     }
                 }
@@ -120,12 +120,12 @@ fun main() {
                         tx2.query("insert into sqlx4k (id) values (66);").getOrThrow()
                         tx2.fetchAll("select * from sqlx4k;") {
                             val id: Sqlx4k.Row.Column = get("id")
-                            Test(id = id.value.toInt())
+                            Test(id = id.value!!.toInt())
                         }
                         tx2.rollback().getOrThrow()
                         pg.fetchAll("select * from sqlx4k;") {
                             val id: Sqlx4k.Row.Column = get("id")
-                            Test(id = id.value.toInt())
+                            Test(id = id.value!!.toInt())
                         }
                     }
                 }
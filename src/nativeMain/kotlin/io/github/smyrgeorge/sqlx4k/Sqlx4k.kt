@@ -53,7 +53,9 @@ class Sqlx4k {
         ) {
             val ordinal: Int get() = column.ordinal
             val type: Type get() = Type.entries[column.kind]
-            val value: String get() = column.value!!.readBytes(column.size).toKString()
+            // NULL values are delivered as a null pointer (unless a null_as sentinel is set).
+            val value: String? get() = column.value?.readBytes(column.size)?.toKString()
+            val isNull: Boolean get() = column.value == null
 
             enum class Type {
                 BOOL,
@@ -73,7 +75,15 @@ class Sqlx4k {
                 BYTEA,
                 UUID,
                 JSON,
-                JSONB
+                JSONB,
+                RECORD,
+                INTERVAL,
+                TIMETZ,
+                MONEY,
+                BIT,
+                INET,
+                CIDR,
+                HSTORE
             }
         }
     }
@@ -5,6 +5,11 @@ import io.github.smyrgeorge.sqlx4k.driver.Driver
 import io.github.smyrgeorge.sqlx4k.driver.Driver.Companion.fn
 import io.github.smyrgeorge.sqlx4k.driver.Transaction
 import kotlinx.cinterop.ExperimentalForeignApi
+import kotlinx.cinterop.alloc
+import kotlinx.cinterop.cstr
+import kotlinx.cinterop.memScoped
+import kotlinx.cinterop.ptr
+import librust_lib.Sqlx4kConnectOptions
 import librust_lib.sqlx4k_fetch_all
 import librust_lib.sqlx4k_of
 import librust_lib.sqlx4k_query
@@ -17,20 +22,35 @@ class Postgres(
     username: String,
     password: String,
     database: String,
-    maxConnections: Int
+    maxConnections: Int,
+    timestampsAsEpoch: Boolean = false,
+    connectTimeoutMillis: Int = 0,
+    rawTextMode: Boolean = false,
+    testBeforeAcquire: Boolean = true,
+    nullAs: String? = null
 ) : Driver, Driver.Tx {
 
     init {
         Driver.init(maxConnections)
 
-        sqlx4k_of(
-            host = host,
-            port = port,
-            username = username,
-            password = password,
-            database = database,
-            max_connections = maxConnections
-        ).orThrow()
+        memScoped {
+            val options = alloc<Sqlx4kConnectOptions> {
+                timestamps_as_epoch = if (timestampsAsEpoch) 1 else 0
+                connect_timeout_millis = connectTimeoutMillis
+                raw_text_mode = if (rawTextMode) 1 else 0
+                test_before_acquire = if (testBeforeAcquire) 1 else 0
+                null_as = nullAs?.cstr?.ptr
+            }
+            sqlx4k_of(
+                host = host,
+                port = port,
+                username = username,
+                password = password,
+                database = database,
+                max_connections = maxConnections,
+                connect_options = options.ptr
+            ).orThrow()
+        }
     }
 
     override suspend fun query(sql: String): Result<Unit> = runCatching {